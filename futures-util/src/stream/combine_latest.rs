@@ -0,0 +1,117 @@
+use futures_core::{Async, Poll, Stream};
+use futures_core::task;
+use stream::zip_latest::enqueue;
+use stream::{Fuse, StreamExt};
+use std::vec::Vec;
+
+/// Adapter combining the latest values of an arbitrary number of streams.
+///
+/// Return type of the `combine_latest` adapter.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct CombineLatest<S: Stream> {
+    streams: Vec<Fuse<S>>,
+    queued: Vec<Option<S::Item>>,
+    fresh: Vec<bool>,
+}
+
+pub fn new<I, S>(streams: I) -> CombineLatest<S>
+    where
+        I: IntoIterator<Item = S>,
+        S: Stream,
+        S::Item: Clone
+{
+    let streams = streams.into_iter().map(StreamExt::fuse).collect::<Vec<_>>();
+    let len = streams.len();
+    CombineLatest {
+        streams: streams,
+        queued: (0..len).map(|_| None).collect(),
+        fresh: (0..len).map(|_| false).collect(),
+    }
+}
+
+impl<S> Stream for CombineLatest<S>
+    where S: Stream,
+          S::Item: Clone
+{
+    type Item = Vec<S::Item>;
+    type Error = S::Error;
+
+    fn poll_next(&mut self, cx: &mut task::Context)
+        -> Poll<Option<Self::Item>, Self::Error>
+    {
+        let mut fresh = false;
+        for i in 0..self.streams.len() {
+            self.fresh[i] = enqueue(&mut self.streams[i], &mut self.queued[i],
+                self.fresh[i], cx)?;
+            fresh = fresh || self.fresh[i];
+        }
+        let done = self.streams.iter().all(Fuse::is_done)
+            || (0..self.streams.len()).any(|i| {
+                self.streams[i].is_done() && self.queued[i].is_none()
+            });
+        let ready = !self.queued.is_empty()
+            && self.queued.iter().all(Option::is_some);
+        if ready && fresh {
+            for flag in &mut self.fresh {
+                *flag = false;
+            }
+            let values = self.queued.iter()
+                .map(|slot| slot.clone().unwrap())
+                .collect();
+            Ok(Async::Ready(Some(values)))
+        } else if done {
+            Ok(Async::Ready(None))
+        } else {
+            Ok(Async::Pending)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+    use futures_executor::block_on;
+    use std::vec::Vec;
+    use stream::{self, StreamExt};
+
+    #[test]
+    fn combine_no_streams() {
+        let streams: Vec<stream::IterOk<::std::vec::IntoIter<i32>, ()>> =
+            Vec::new();
+        let res = stream::combine_latest(streams).collect();
+        assert_eq!(block_on(res), Ok(Vec::<Vec<i32>>::new()));
+    }
+
+    #[test]
+    fn combine_empty_and_other() {
+        let streams = vec![stream::iter_ok::<_, ()>(Vec::new()),
+            stream::iter_ok(vec![0, 1, 2])];
+        let res = stream::combine_latest(streams).collect();
+        assert_eq!(block_on(res), Ok(Vec::<Vec<i32>>::new()));
+    }
+
+    #[test]
+    fn combine_three_streams() {
+        let streams = vec![
+            stream::iter_ok::<_, ()>(vec![0, 1]),
+            stream::iter_ok(vec![10]),
+            stream::iter_ok(vec![20, 21]),
+        ];
+        let res = stream::combine_latest(streams).collect();
+        assert_eq!(block_on(res), Ok(vec![
+            vec![0, 10, 20],
+            vec![1, 10, 21],
+        ]));
+    }
+
+    #[test]
+    fn combine_terminates() {
+        let streams = vec![
+            stream::iter_ok::<_, ()>(vec![0]),
+            stream::iter_ok(vec![1, 2]),
+        ];
+        let res = stream::combine_latest(streams).collect();
+        assert_eq!(block_on(res), Ok(vec![vec![0, 1], vec![0, 2]]));
+    }
+}
@@ -0,0 +1,108 @@
+use futures_core::{Async, Poll, Stream};
+use futures_core::task;
+
+/// Stream for the `poll_immediate` adapter.
+///
+/// Yields whatever is immediately available from the inner stream, surfacing
+/// a "would block" as an `Async::Pending` item. If the inner stream is still
+/// not ready when polled right after such an item, this stream returns
+/// `Async::Pending` itself instead of yielding a second one.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct PollImmediate<S> {
+    stream: Option<S>,
+    peeked_pending: bool,
+}
+
+pub fn new<S: Stream>(stream: S) -> PollImmediate<S> {
+    PollImmediate {
+        stream: Some(stream),
+        peeked_pending: false,
+    }
+}
+
+impl<S: Stream> Stream for PollImmediate<S> {
+    type Item = Async<S::Item>;
+    type Error = S::Error;
+
+    fn poll_next(&mut self, cx: &mut task::Context)
+        -> Poll<Option<Self::Item>, Self::Error>
+    {
+        let item = match self.stream {
+            Some(ref mut stream) => stream.poll_next(cx)?,
+            None => return Ok(Async::Ready(None)),
+        };
+        match item {
+            Async::Ready(Some(x)) => {
+                self.peeked_pending = false;
+                Ok(Async::Ready(Some(Async::Ready(x))))
+            }
+            Async::Ready(None) => {
+                self.stream = None;
+                Ok(Async::Ready(None))
+            }
+            Async::Pending if self.peeked_pending => {
+                self.peeked_pending = false;
+                Ok(Async::Pending)
+            }
+            Async::Pending => {
+                self.peeked_pending = true;
+                Ok(Async::Ready(Some(Async::Pending)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+    use futures_core::{Async, Poll, Stream};
+    use futures_executor::block_on;
+    use std::boxed::Box;
+    use std::vec::Vec;
+    use stream::{self, StreamExt};
+
+    #[test]
+    fn poll_immediate_drains_ready() {
+        let res = stream::iter_ok::<_, ()>(0..3).poll_immediate().collect();
+        assert_eq!(block_on(res), Ok(vec![
+            Async::Ready(0),
+            Async::Ready(1),
+            Async::Ready(2),
+        ]));
+    }
+
+    #[test]
+    fn poll_immediate_surfaces_pending() {
+        let values = vec![
+            Ok::<_, ()>(Async::Ready(0)),
+            Ok(Async::Pending),
+            Ok(Async::Ready(1)),
+        ];
+        let res = stream_from_poll_iter(values).poll_immediate().collect();
+        assert_eq!(block_on(res), Ok(vec![
+            Async::Ready(0),
+            Async::Pending,
+            Async::Ready(1),
+        ]));
+    }
+
+    fn stream_from_poll_iter<'a, I, T, E>(list: I)
+        -> Box<Stream<Item = T, Error = E> + 'a>
+        where
+            I: IntoIterator<Item = Poll<T, E>>,
+            I::IntoIter: 'a
+    {
+        let mut list = list.into_iter();
+        let st = stream::poll_fn(move |cx| match list.next() {
+            Some(Ok(Async::Ready(x))) => Ok(Async::Ready(Some(x))),
+            Some(Ok(Async::Pending)) => {
+                cx.waker().wake();
+                Ok(Async::Pending)
+            }
+            Some(Err(e)) => Err(e),
+            None => Ok(Async::Ready(None)),
+        });
+        Box::new(st)
+    }
+}
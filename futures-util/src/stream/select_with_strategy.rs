@@ -0,0 +1,122 @@
+use futures_core::{Async, Poll, Stream};
+use futures_core::task;
+use stream::{Fuse, StreamExt};
+
+/// Which stream to poll first on a given round of `SelectWithStrategy`.
+///
+/// Returned by the strategy closure passed to `select_with_strategy`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PollNext {
+    /// Poll the first (left) stream first.
+    Left,
+    /// Poll the second (right) stream first.
+    Right,
+}
+
+/// Adapter merging two streams of the same item type under a user-supplied
+/// polling strategy.
+///
+/// Return type of the `select_with_strategy` adapter.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct SelectWithStrategy<S1: Stream, S2: Stream, State, Clo> {
+    stream1: Fuse<S1>,
+    stream2: Fuse<S2>,
+    state: State,
+    clo: Clo,
+}
+
+pub fn new<S1, S2, State, Clo>(stream1: S1, stream2: S2, state: State, clo: Clo)
+    -> SelectWithStrategy<S1, S2, State, Clo>
+    where
+        S1: Stream,
+        S2: Stream<Item = S1::Item, Error = S1::Error>,
+        Clo: FnMut(&mut State) -> PollNext
+{
+    SelectWithStrategy {
+        stream1: stream1.fuse(),
+        stream2: stream2.fuse(),
+        state: state,
+        clo: clo,
+    }
+}
+
+impl<S1, S2, State, Clo> Stream for SelectWithStrategy<S1, S2, State, Clo>
+    where S1: Stream,
+          S2: Stream<Item = S1::Item, Error = S1::Error>,
+          Clo: FnMut(&mut State) -> PollNext
+{
+    type Item = S1::Item;
+    type Error = S1::Error;
+
+    fn poll_next(&mut self, cx: &mut task::Context)
+        -> Poll<Option<Self::Item>, Self::Error>
+    {
+        match (self.clo)(&mut self.state) {
+            PollNext::Left => {
+                if let Async::Ready(Some(item)) = self.stream1.poll_next(cx)? {
+                    return Ok(Async::Ready(Some(item)));
+                }
+                if let Async::Ready(Some(item)) = self.stream2.poll_next(cx)? {
+                    return Ok(Async::Ready(Some(item)));
+                }
+            }
+            PollNext::Right => {
+                if let Async::Ready(Some(item)) = self.stream2.poll_next(cx)? {
+                    return Ok(Async::Ready(Some(item)));
+                }
+                if let Async::Ready(Some(item)) = self.stream1.poll_next(cx)? {
+                    return Ok(Async::Ready(Some(item)));
+                }
+            }
+        }
+        if self.stream1.is_done() && self.stream2.is_done() {
+            Ok(Async::Ready(None))
+        } else {
+            Ok(Async::Pending)
+        }
+    }
+}
+
+/// Round-robin merge of two streams, alternating which side is polled first.
+pub fn select<S1, S2>(stream1: S1, stream2: S2)
+    -> SelectWithStrategy<S1, S2, PollNext, fn(&mut PollNext) -> PollNext>
+    where
+        S1: Stream,
+        S2: Stream<Item = S1::Item, Error = S1::Error>
+{
+    fn round_robin(last: &mut PollNext) -> PollNext {
+        let next = *last;
+        *last = match *last {
+            PollNext::Left => PollNext::Right,
+            PollNext::Right => PollNext::Left,
+        };
+        next
+    }
+    new(stream1, stream2, PollNext::Left, round_robin)
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+    use futures_executor::block_on;
+    use std::vec::Vec;
+    use stream::{self, StreamExt};
+    use stream::select_with_strategy::select;
+
+    #[test]
+    fn select_round_robin_drains_both() {
+        let res = select(stream::iter_ok::<_, ()>(0..3),
+            stream::iter_ok(10..13)).collect();
+        let mut out = block_on(res).unwrap();
+        out.sort();
+        assert_eq!(out, vec![0, 1, 2, 10, 11, 12]);
+    }
+
+    #[test]
+    fn select_empty_sides() {
+        let res = select(stream::iter_ok::<_, ()>(Vec::<i32>::new()),
+            stream::iter_ok(Vec::new())).collect();
+        assert_eq!(block_on(res), Ok(Vec::<i32>::new()));
+    }
+}
@@ -0,0 +1,79 @@
+use futures_core::{Async, Poll, Stream};
+use futures_core::task;
+use stream::zip_latest::enqueue;
+use stream::{Fuse, StreamExt};
+
+/// Adapter pairing each item of a primary stream with the latest value of
+/// another stream.
+///
+/// Return type of the `with_latest_from` adapter.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct WithLatestFrom<S1: Stream, S2: Stream> {
+    stream1: Fuse<S1>,
+    stream2: Fuse<S2>,
+    queued2: Option<S2::Item>,
+}
+
+pub fn new<S1, S2>(stream1: S1, stream2: S2) -> WithLatestFrom<S1, S2>
+    where
+        S1: Stream,
+        S2: Stream<Error = S1::Error>,
+        S2::Item: Clone
+{
+    WithLatestFrom {
+        stream1: stream1.fuse(),
+        stream2: stream2.fuse(),
+        queued2: None,
+    }
+}
+
+impl<S1, S2> Stream for WithLatestFrom<S1, S2>
+    where S1: Stream,
+          S2: Stream<Error = S1::Error>,
+          S2::Item: Clone
+{
+    type Item = (S1::Item, S2::Item);
+    type Error = S1::Error;
+
+    fn poll_next(&mut self, cx: &mut task::Context)
+        -> Poll<Option<Self::Item>, Self::Error>
+    {
+        enqueue(&mut self.stream2, &mut self.queued2, false, cx)?;
+        loop {
+            match self.stream1.poll_next(cx)? {
+                Async::Ready(Some(item1)) => {
+                    if let Some(ref item2) = self.queued2 {
+                        return Ok(Async::Ready(Some((item1, item2.clone()))));
+                    }
+                }
+                Async::Ready(None) => return Ok(Async::Ready(None)),
+                Async::Pending => return Ok(Async::Pending),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+    use futures_executor::block_on;
+    use std::vec::Vec;
+    use stream::{self, StreamExt};
+
+    #[test]
+    fn with_latest_drops_before_secondary_fires() {
+        let res = stream::iter_ok::<_, ()>(0..3)
+            .with_latest_from(stream::iter_ok(Vec::<i32>::new()))
+            .collect();
+        assert_eq!(block_on(res), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn with_latest_pairs_with_latest_secondary() {
+        let res = stream::iter_ok::<_, ()>(0..3)
+            .with_latest_from(stream::iter_ok(vec![10, 11]))
+            .collect();
+        assert_eq!(block_on(res), Ok(vec![(0, 10), (1, 11), (2, 11)]));
+    }
+}
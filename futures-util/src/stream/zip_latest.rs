@@ -65,7 +65,7 @@ impl<S1, S2> Stream for ZipLatest<S1, S2>
     }
 }
 
-fn enqueue<S: Stream>(stream: &mut S, queued: &mut Option<S::Item>,
+pub(crate) fn enqueue<S: Stream>(stream: &mut S, queued: &mut Option<S::Item>,
     queued_fresh: bool, cx: &mut task::Context) -> Result<bool, S::Error>
 {
     if queued.is_none() || !queued_fresh {
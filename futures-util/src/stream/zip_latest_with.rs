@@ -0,0 +1,94 @@
+use futures_core::{Async, Poll, Stream};
+use futures_core::task;
+use stream::zip_latest::enqueue;
+use stream::{Fuse, StreamExt};
+
+/// Adapter to zip two streams using their latest values, combining them with
+/// a closure.
+///
+/// Return type of the `zip_latest_with` adapter.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct ZipLatestWith<S1: Stream, S2: Stream, F> {
+    stream1: Fuse<S1>,
+    stream2: Fuse<S2>,
+    queued1: Option<S1::Item>,
+    queued1_fresh: bool,
+    queued2: Option<S2::Item>,
+    queued2_fresh: bool,
+    f: F,
+}
+
+pub fn new<S1, S2, F, T>(stream1: S1, stream2: S2, f: F)
+    -> ZipLatestWith<S1, S2, F>
+    where
+        S1: Stream,
+        S2: Stream<Error = S1::Error>,
+        F: FnMut(&S1::Item, &S2::Item) -> T
+{
+    ZipLatestWith {
+        stream1: stream1.fuse(),
+        stream2: stream2.fuse(),
+        queued1: None,
+        queued1_fresh: false,
+        queued2: None,
+        queued2_fresh: false,
+        f: f,
+    }
+}
+
+impl<S1, S2, F, T> Stream for ZipLatestWith<S1, S2, F>
+    where S1: Stream,
+          S2: Stream<Error = S1::Error>,
+          F: FnMut(&S1::Item, &S2::Item) -> T
+{
+    type Item = T;
+    type Error = S1::Error;
+
+    fn poll_next(&mut self, cx: &mut task::Context)
+        -> Poll<Option<Self::Item>, Self::Error>
+    {
+        self.queued1_fresh = enqueue(&mut self.stream1, &mut self.queued1,
+            self.queued1_fresh, cx)?;
+        self.queued2_fresh = enqueue(&mut self.stream2, &mut self.queued2,
+            self.queued2_fresh, cx)?;
+        let fresh = self.queued1_fresh || self.queued2_fresh;
+        let done = self.stream1.is_done() && self.stream2.is_done()
+            || self.stream1.is_done() && self.queued1.is_none()
+            || self.stream2.is_done() && self.queued2.is_none();
+        match (&self.queued1, &self.queued2) {
+            (&Some(ref item1), &Some(ref item2)) if fresh => {
+                self.queued1_fresh = false;
+                self.queued2_fresh = false;
+                Ok(Async::Ready(Some((self.f)(item1, item2))))
+            }
+            _ if done => Ok(Async::Ready(None)),
+            _ => Ok(Async::Pending),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+    use futures_executor::block_on;
+    use std::vec::Vec;
+    use stream::{self, StreamExt};
+
+    #[test]
+    fn zip_with_sum_same_length() {
+        let res = stream::iter_ok::<_, ()>(0..3)
+            .zip_latest_with(stream::iter_ok(0..3), |a, b| a + b)
+            .collect();
+        let expected = (0..3).map(|x| x + x).collect::<Vec<_>>();
+        assert_eq!(block_on(res), Ok(expected));
+    }
+
+    #[test]
+    fn zip_with_long_short() {
+        let res = stream::iter_ok::<_, ()>(0..3)
+            .zip_latest_with(stream::iter_ok(0..2), |a, b| (*a, *b))
+            .collect();
+        assert_eq!(block_on(res), Ok(vec![(0, 0), (1, 1), (2, 1)]));
+    }
+}